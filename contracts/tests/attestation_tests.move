@@ -0,0 +1,109 @@
+#[test_only]
+module truth_markets::attestation_tests {
+    use sui::test_scenario::{Self as ts, Scenario};
+    use sui::clock::{Self, Clock};
+    use std::string;
+    use truth_markets::attestation::{Self, MeasurementRegistry};
+    use truth_markets::claim_registry::{Self, Claim, ClaimRegistry};
+
+    const ADMIN: address = @0xA;
+    const DEADLINE: u64 = 1000;
+
+    fun repeat_byte(byte: u8, len: u64): vector<u8> {
+        let mut bytes = std::vector::empty<u8>();
+        let mut i = 0;
+        while (i < len) {
+            std::vector::push_back(&mut bytes, byte);
+            i = i + 1;
+        };
+        bytes
+    }
+
+    fun measurement(): vector<u8> { repeat_byte(1, 32) }
+
+    /// Whitelist `measurement()` and create one open claim with `DEADLINE`
+    fun setup(scenario: &mut Scenario, clock: &Clock) {
+        attestation::init_for_testing(ts::ctx(scenario));
+        claim_registry::init_for_testing(ts::ctx(scenario));
+        ts::next_tx(scenario, ADMIN);
+
+        let mut registry = ts::take_shared<MeasurementRegistry>(scenario);
+        attestation::whitelist_measurement(
+            &mut registry,
+            measurement(),
+            string::utf8(b"Weather Oracle v1.0"),
+            clock,
+            ts::ctx(scenario),
+        );
+        ts::return_shared(registry);
+
+        let mut claims = ts::take_shared<ClaimRegistry>(scenario);
+        claim_registry::create_claim(
+            &mut claims,
+            string::utf8(b"spec"),
+            string::utf8(b"evidence"),
+            string::utf8(b"London temperature > 10C"),
+            DEADLINE,
+            0,
+            clock,
+            ts::ctx(scenario),
+        );
+        ts::return_shared(claims);
+        ts::next_tx(scenario, ADMIN);
+    }
+
+    /// Run `setup`, advance past the deadline and submit `payload` as a YES result
+    fun submit(payload: vector<u8>) {
+        let mut scenario = ts::begin(ADMIN);
+        let mut clock = clock::create_for_testing(ts::ctx(&mut scenario));
+        setup(&mut scenario, &clock);
+        clock::set_for_testing(&mut clock, DEADLINE);
+
+        let registry = ts::take_shared<MeasurementRegistry>(&scenario);
+        let mut claim = ts::take_shared<Claim>(&scenario);
+        attestation::submit_attested_resolution(
+            &registry,
+            &mut claim,
+            true,
+            string::utf8(b"result"),
+            payload,
+            &clock,
+            ts::ctx(&mut scenario),
+        );
+
+        assert!(claim_registry::is_resolved(&claim), 0);
+        assert!(claim_registry::get_result(&claim) == std::option::some(true), 1);
+
+        ts::return_shared(claim);
+        ts::return_shared(registry);
+        clock::destroy_for_testing(clock);
+        ts::end(scenario);
+    }
+
+    #[test]
+    fun test_submit_with_measurement_only() {
+        submit(measurement());
+    }
+
+    #[test]
+    fun test_submit_ignores_bytes_after_measurement() {
+        // Timestamp (8 bytes) and signature (64 bytes) follow the measurement
+        // but are not parsed on-chain yet
+        let mut payload = measurement();
+        std::vector::append(&mut payload, repeat_byte(0xEE, 8));
+        std::vector::append(&mut payload, repeat_byte(0xFF, 64));
+        submit(payload);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = truth_markets::attestation::EInvalidAttestation)]
+    fun test_submit_rejects_short_payload() {
+        submit(repeat_byte(1, 31));
+    }
+
+    #[test]
+    #[expected_failure(abort_code = truth_markets::attestation::EMeasurementNotWhitelisted)]
+    fun test_submit_rejects_unlisted_measurement() {
+        submit(repeat_byte(2, 32));
+    }
+}